@@ -0,0 +1,269 @@
+use std::ops::{Shl, Shr};
+
+use crate::ops::{mag_add, mag_mul, trim};
+use crate::{BigInt, BASE};
+
+// 2^32, written out in base-BASE limbs (little-endian), used to convert
+// between the decimal-limb and binary-limb representations.
+fn two_pow_32_digits() -> [u32; 2] {
+    [294_967_296, 4]
+}
+
+fn encode_u32(v: u32) -> Vec<u32> {
+    let lo = v % BASE;
+    let hi = v / BASE;
+    match (lo, hi) {
+        (0, 0) => Vec::new(),
+        (lo, 0) => vec![lo],
+        (lo, hi) => vec![lo, hi],
+    }
+}
+
+// `digits * scalar + add`, in base 2^32 (little-endian).
+fn bin_mul_add(digits: &[u32], scalar: u32, add: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(digits.len() + 1);
+    let mut carry = add as u64;
+    for &limb in digits {
+        let prod = limb as u64 * scalar as u64 + carry;
+        result.push(prod as u32);
+        carry = prod >> 32;
+    }
+    while carry > 0 {
+        result.push(carry as u32);
+        carry >>= 32;
+    }
+    result
+}
+
+/// Converts base-`BASE` decimal limbs into base-2^32 binary limbs.
+fn to_binary(digits: &[u32]) -> Vec<u32> {
+    let mut bin = Vec::new();
+    for &d in digits.iter().rev() {
+        bin = bin_mul_add(&bin, BASE, d);
+    }
+    trim(bin)
+}
+
+/// Converts base-2^32 binary limbs back into base-`BASE` decimal limbs.
+fn from_binary(bin: &[u32]) -> Vec<u32> {
+    let mut digits = Vec::new();
+    for &limb in bin.iter().rev() {
+        digits = mag_mul(&digits, &two_pow_32_digits());
+        digits = mag_add(&digits, &encode_u32(limb));
+    }
+    trim(digits)
+}
+
+fn shl_bin(bin: &[u32], shift: usize) -> Vec<u32> {
+    let limb_shift = shift / 32;
+    let bit_shift = shift % 32;
+
+    let mut result = vec![0u32; limb_shift];
+    if bit_shift == 0 {
+        result.extend_from_slice(bin);
+    } else {
+        let mut carry = 0u32;
+        for &limb in bin {
+            let widened = (limb as u64) << bit_shift;
+            result.push(widened as u32 | carry);
+            carry = (widened >> 32) as u32;
+        }
+        if carry > 0 {
+            result.push(carry);
+        }
+    }
+    trim(result)
+}
+
+fn shr_bin(bin: &[u32], shift: usize) -> Vec<u32> {
+    let limb_shift = shift / 32;
+    let bit_shift = shift % 32;
+
+    if limb_shift >= bin.len() {
+        return Vec::new();
+    }
+    let src = &bin[limb_shift..];
+
+    if bit_shift == 0 {
+        return trim(src.to_vec());
+    }
+
+    let mut result = Vec::with_capacity(src.len());
+    for i in 0..src.len() {
+        let next = src.get(i + 1).copied().unwrap_or(0);
+        let limb = (src[i] >> bit_shift) | (next << (32 - bit_shift));
+        result.push(limb);
+    }
+    trim(result)
+}
+
+/// Keeps only the low `n_bits` bits of `bin`.
+fn mask_bin(bin: &[u32], n_bits: usize) -> Vec<u32> {
+    if n_bits == 0 {
+        return Vec::new();
+    }
+
+    let full_limbs = n_bits / 32;
+    let rem_bits = n_bits % 32;
+
+    let mut result = bin[..full_limbs.min(bin.len())].to_vec();
+    if rem_bits > 0 {
+        if let Some(&limb) = bin.get(full_limbs) {
+            result.push(limb & ((1u32 << rem_bits) - 1));
+        }
+    }
+    trim(result)
+}
+
+impl BigInt {
+    /// Returns whether bit `idx` (0 = least significant) is set in the
+    /// magnitude.
+    pub fn bit(&self, idx: usize) -> bool {
+        let limb_idx = idx / 32;
+        let bit_idx = idx % 32;
+        to_binary(&self.digits)
+            .get(limb_idx)
+            .is_some_and(|limb| (limb >> bit_idx) & 1 == 1)
+    }
+
+    /// Returns the bits in `[start, end)` of the magnitude as a new,
+    /// non-negative `BigInt`.
+    pub fn bit_slice(&self, start: usize, end: usize) -> BigInt {
+        assert!(start <= end, "bit_slice range must be non-decreasing");
+        let bin = to_binary(&self.digits);
+        let sliced = mask_bin(&shr_bin(&bin, start), end - start);
+        BigInt {
+            neg: false,
+            digits: from_binary(&sliced),
+        }
+        .normalize()
+    }
+
+    /// Keeps only the low `n` bits of the magnitude.
+    pub fn mask(&self, n: usize) -> BigInt {
+        let bin = mask_bin(&to_binary(&self.digits), n);
+        BigInt {
+            neg: false,
+            digits: from_binary(&bin),
+        }
+        .normalize()
+    }
+
+    /// Returns the number of trailing zero bits in the magnitude, or `None`
+    /// if the value is zero.
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let bin = to_binary(&self.digits);
+        let mut count = 0;
+        for &limb in &bin {
+            if limb == 0 {
+                count += 32;
+            } else {
+                return Some(count + limb.trailing_zeros() as usize);
+            }
+        }
+        None
+    }
+}
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, rhs: usize) -> BigInt {
+        let bin = shl_bin(&to_binary(&self.digits), rhs);
+        BigInt {
+            neg: self.neg,
+            digits: from_binary(&bin),
+        }
+        .normalize()
+    }
+}
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+
+    fn shl(self, rhs: usize) -> BigInt {
+        &self << rhs
+    }
+}
+
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shr(self, rhs: usize) -> BigInt {
+        let bin = shr_bin(&to_binary(&self.digits), rhs);
+        BigInt {
+            neg: self.neg,
+            digits: from_binary(&bin),
+        }
+        .normalize()
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+
+    fn shr(self, rhs: usize) -> BigInt {
+        &self >> rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_reads_low_and_high_bits() {
+        let v = BigInt::from_i64(0b1011);
+        assert!(v.bit(0));
+        assert!(v.bit(1));
+        assert!(!v.bit(2));
+        assert!(v.bit(3));
+        assert!(!v.bit(100));
+    }
+
+    #[test]
+    fn bit_round_trips_across_the_decimal_binary_boundary() {
+        // Large enough to span several base-2^32 limbs.
+        let v: BigInt = "123456789012345678901234567890123456789".parse().unwrap();
+        for idx in [0usize, 1, 31, 32, 33, 63, 64, 127, 128] {
+            let expected = (v.to_str_radix(2).chars().rev().nth(idx)).unwrap_or('0') == '1';
+            assert_eq!(v.bit(idx), expected, "bit {idx}");
+        }
+    }
+
+    #[test]
+    fn mask_keeps_low_bits_only() {
+        let v = BigInt::from_i64(0b1111_0000);
+        assert_eq!(v.mask(4), BigInt::zero());
+        assert_eq!(v.mask(8), v);
+    }
+
+    #[test]
+    fn bit_slice_extracts_middle_bits() {
+        let v = BigInt::from_i64(0b1101_0110);
+        assert_eq!(v.bit_slice(1, 4), BigInt::from_i64(0b011));
+    }
+
+    #[test]
+    fn trailing_zeros_counts_low_zero_bits() {
+        assert_eq!(BigInt::from_i64(0b1000).trailing_zeros(), Some(3));
+        assert_eq!(BigInt::from_i64(1).trailing_zeros(), Some(0));
+        assert_eq!(BigInt::zero().trailing_zeros(), None);
+    }
+
+    #[test]
+    fn shl_and_shr_are_inverse_on_exact_multiples() {
+        let v = BigInt::from_i64(12345);
+        let shifted = &v << 40;
+        assert_eq!(&shifted >> 40, v);
+        assert_eq!(shifted.trailing_zeros(), Some(40));
+    }
+
+    #[test]
+    fn shr_preserves_sign_of_nonzero_result() {
+        let v = BigInt::from_i64(-1024);
+        let shifted = v >> 2;
+        assert!(shifted.neg);
+        assert_eq!(shifted, BigInt::from_i64(-256));
+    }
+}
@@ -0,0 +1,100 @@
+use std::hash::{Hash, Hasher};
+
+use crate::BigInt;
+
+/// A constant seed hashed before anything else, so that `BigInt::zero()`
+/// doesn't collide with a hasher's initial state.
+const HASH_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Returns `digits` with any trailing (i.e. most-significant, since the
+/// representation is little-endian) zero limbs dropped. `normalize()` should
+/// already guarantee this, but `Eq`/`Hash` stay defensive about stray slack
+/// so the two never disagree.
+fn significant_digits(digits: &[u32]) -> &[u32] {
+    let mut len = digits.len();
+    while len > 0 && digits[len - 1] == 0 {
+        len -= 1;
+    }
+    &digits[..len]
+}
+
+impl PartialEq for BigInt {
+    fn eq(&self, other: &Self) -> bool {
+        let a = significant_digits(&self.digits);
+        let b = significant_digits(&other.digits);
+
+        if a.is_empty() && b.is_empty() {
+            return true;
+        }
+
+        self.neg == other.neg && a == b
+    }
+}
+
+impl Eq for BigInt {}
+
+impl Hash for BigInt {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(HASH_SEED);
+
+        let sig = significant_digits(&self.digits);
+        let neg = self.neg && !sig.is_empty();
+        neg.hash(state);
+
+        for &limb in sig.iter().rev() {
+            limb.hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of(v: &BigInt) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn equal_values_hash_identically_despite_slack() {
+        let clean = BigInt::from_i64(42);
+        let slack = BigInt {
+            neg: false,
+            digits: vec![42, 0, 0],
+        };
+
+        assert_eq!(clean, slack);
+        assert_eq!(hash_of(&clean), hash_of(&slack));
+    }
+
+    #[test]
+    fn positive_and_negative_zero_are_equal_and_same_hash() {
+        let zero = BigInt::zero();
+        let neg_zero = BigInt {
+            neg: true,
+            digits: Vec::new(),
+        };
+
+        assert_eq!(zero, neg_zero);
+        assert_eq!(hash_of(&zero), hash_of(&neg_zero));
+    }
+
+    #[test]
+    fn different_values_are_not_equal() {
+        assert_ne!(BigInt::from_i64(1), BigInt::from_i64(-1));
+        assert_ne!(BigInt::from_i64(1), BigInt::from_i64(2));
+    }
+
+    #[test]
+    fn works_as_hashmap_key() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(BigInt::from_i64(1_000_000_001), "a");
+        assert_eq!(map.get(&BigInt::from_i64(1_000_000_001)), Some(&"a"));
+        assert_eq!(map.get(&BigInt::from_i64(1_000_000_002)), None);
+    }
+}
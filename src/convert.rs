@@ -0,0 +1,174 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::division::div_rem_digit;
+use crate::ops::{mag_add, mag_mul};
+use crate::{BigInt, BASE};
+
+/// Error returned by [`BigInt::from_str`](std::str::FromStr::from_str).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBigIntError::Empty => f.write_str("cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit => f.write_str("invalid digit found in string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        if rest.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+
+        let mut digits = Vec::new();
+        for ch in rest.chars() {
+            let digit = ch.to_digit(10).ok_or(ParseBigIntError::InvalidDigit)?;
+            digits = mag_add(&mag_mul(&digits, &[10]), &[digit]);
+        }
+
+        Ok(BigInt { neg, digits }.normalize())
+    }
+}
+
+impl BigInt {
+    /// Formats the value in the given `radix` (2..=36), most-significant
+    /// digit first.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+        if self.digits.is_empty() {
+            return "0".to_string();
+        }
+
+        // Largest power of `radix` that does not exceed BASE, so each
+        // div_rem_digit pass peels off as many radix digits as possible.
+        let mut max_len = 0usize;
+        let mut chunk_base: u64 = 1;
+        while chunk_base * radix as u64 <= BASE as u64 {
+            chunk_base *= radix as u64;
+            max_len += 1;
+        }
+        let chunk_base = chunk_base as u32;
+
+        let mut chunks = Vec::new();
+        let mut mag = self.digits.clone();
+        while !mag.is_empty() {
+            let (q, r) = div_rem_digit(&mag, chunk_base);
+            chunks.push(r);
+            mag = q;
+        }
+
+        let mut out = String::new();
+        if self.neg {
+            out.push('-');
+        }
+        for (i, &chunk) in chunks.iter().rev().enumerate() {
+            let digits = radix_digits(chunk, radix);
+            if i > 0 {
+                for _ in 0..max_len - digits.len() {
+                    out.push('0');
+                }
+            }
+            out.push_str(&digits);
+        }
+        out
+    }
+}
+
+fn radix_digits(mut value: u32, radix: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    while value > 0 {
+        digits.push(std::char::from_digit(value % radix, radix).unwrap());
+        value /= radix;
+    }
+    digits.iter().rev().collect()
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_round_trips_decimal() {
+        for s in [
+            "0",
+            "1",
+            "-1",
+            "999999999000000001",
+            "-123456789012345678901234567890",
+        ] {
+            let v: BigInt = s.parse().unwrap();
+            assert_eq!(v.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn rejects_empty_and_bad_input() {
+        assert_eq!("".parse::<BigInt>().unwrap_err(), ParseBigIntError::Empty);
+        assert_eq!("-".parse::<BigInt>().unwrap_err(), ParseBigIntError::Empty);
+        assert_eq!(
+            "12a3".parse::<BigInt>().unwrap_err(),
+            ParseBigIntError::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn negative_zero_normalizes_to_non_negative() {
+        let v: BigInt = "-0".parse().unwrap();
+        assert_eq!(v.to_string(), "0");
+    }
+
+    #[test]
+    fn formats_hex_and_binary() {
+        let v = BigInt::from_i64(255);
+        assert_eq!(v.to_str_radix(16), "ff");
+        assert_eq!(v.to_str_radix(2), "11111111");
+
+        let neg = BigInt::from_i64(-255);
+        assert_eq!(neg.to_str_radix(16), "-ff");
+    }
+
+    #[test]
+    fn zero_formats_as_zero_in_any_radix() {
+        assert_eq!(BigInt::zero().to_str_radix(16), "0");
+        assert_eq!(BigInt::zero().to_str_radix(36), "0");
+    }
+
+    #[test]
+    fn debug_matches_display() {
+        let v = BigInt::from_i64(-42);
+        assert_eq!(format!("{:?}", v), format!("{}", v));
+    }
+}
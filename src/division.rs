@@ -0,0 +1,257 @@
+use std::ops::{Div, Rem};
+
+use crate::ops::{cmp_magnitude, mag_mul, trim};
+use crate::{BigInt, BASE};
+
+impl BigInt {
+    /// Returns `(self / divisor, self % divisor)` using truncated division:
+    /// the remainder takes the sign of `self`, matching Rust's integer
+    /// division semantics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        if divisor.digits.is_empty() {
+            panic!("division by zero");
+        }
+
+        if cmp_magnitude(&self.digits, &divisor.digits) == std::cmp::Ordering::Less {
+            return (BigInt::zero(), self.clone());
+        }
+
+        let (quotient_digits, remainder_digits) = if divisor.digits.len() == 1 {
+            let (q, r) = div_rem_digit(&self.digits, divisor.digits[0]);
+            (q, if r == 0 { Vec::new() } else { vec![r] })
+        } else {
+            mag_div_rem(&self.digits, &divisor.digits)
+        };
+
+        let quotient = BigInt {
+            neg: self.neg ^ divisor.neg,
+            digits: quotient_digits,
+        }
+        .normalize();
+        let remainder = BigInt {
+            neg: self.neg,
+            digits: remainder_digits,
+        }
+        .normalize();
+
+        (quotient, remainder)
+    }
+}
+
+/// Divides the magnitude `a` by the single limb `divisor`, most-significant
+/// limb first, carrying the running remainder between limbs.
+pub(crate) fn div_rem_digit(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem: u64 = 0;
+    for i in (0..a.len()).rev() {
+        let cur = rem * BASE as u64 + a[i] as u64;
+        quotient[i] = (cur / divisor as u64) as u32;
+        rem = cur % divisor as u64;
+    }
+    (trim(quotient), rem as u32)
+}
+
+/// Knuth's Algorithm D, adapted from base 2 to base `BASE`: normalize so the
+/// divisor's top limb is large, then estimate each quotient limb from the
+/// top two limbs of the running remainder and correct downward when the
+/// trial product overshoots.
+fn mag_div_rem(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let n = v.len();
+    let m = u.len() - n;
+
+    let d = (BASE as u64 / (v[n - 1] as u64 + 1)) as u32;
+
+    let mut v_norm = mag_mul(v, &[d]);
+    v_norm.truncate(n); // top limb is provably 0: d is chosen so v*d < BASE^n
+
+    // With a one-limb multiplier mag_mul takes the schoolbook path, which
+    // does not trim, so u*d is always u.len()+1 limbs (the extra one is what
+    // lets the loop below read u_norm[j + n] for every j).
+    let mut u_norm = mag_mul(u, &[d]);
+    debug_assert_eq!(u_norm.len(), u.len() + 1);
+
+    let mut quotient = vec![0u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let top = u_norm[j + n] as u64 * BASE as u64 + u_norm[j + n - 1] as u64;
+        let mut qhat = top / v_norm[n - 1] as u64;
+        let mut rhat = top % v_norm[n - 1] as u64;
+
+        while rhat < BASE as u64
+            && (qhat >= BASE as u64
+                || qhat * v_norm[n - 2] as u64 > rhat * BASE as u64 + u_norm[j + n - 2] as u64)
+        {
+            qhat -= 1;
+            rhat += v_norm[n - 1] as u64;
+        }
+
+        let mut borrow: i64 = 0;
+        let mut carry: u64 = 0;
+        for i in 0..n {
+            let prod = qhat * v_norm[i] as u64 + carry;
+            carry = prod / BASE as u64;
+            let mut diff = u_norm[j + i] as i64 - (prod % BASE as u64) as i64 - borrow;
+            if diff < 0 {
+                diff += BASE as i64;
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+            u_norm[j + i] = diff as u32;
+        }
+
+        let mut top_diff = u_norm[j + n] as i64 - carry as i64 - borrow;
+        if top_diff < 0 {
+            // qhat was one too large: add the divisor back in.
+            qhat -= 1;
+            let mut carry_back = 0u64;
+            for i in 0..n {
+                let sum = u_norm[j + i] as u64 + v_norm[i] as u64 + carry_back;
+                u_norm[j + i] = (sum % BASE as u64) as u32;
+                carry_back = sum / BASE as u64;
+            }
+            top_diff += BASE as i64 + carry_back as i64;
+        }
+        u_norm[j + n] = top_diff as u32;
+
+        quotient[j] = qhat as u32;
+    }
+
+    let (remainder, _) = div_rem_digit(&u_norm[0..n], d);
+    (trim(quotient), remainder)
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: BigInt) -> BigInt {
+        &self / &rhs
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: BigInt) -> BigInt {
+        &self % &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(dividend: i64, divisor: i64) {
+        let a = BigInt::from_i64(dividend);
+        let b = BigInt::from_i64(divisor);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q.digits, BigInt::from_i64(dividend / divisor).digits);
+        assert_eq!(q.neg, BigInt::from_i64(dividend / divisor).neg);
+        assert_eq!(r.digits, BigInt::from_i64(dividend % divisor).digits);
+        assert_eq!(r.neg, BigInt::from_i64(dividend % divisor).neg);
+    }
+
+    #[test]
+    fn single_limb_divisor() {
+        check(1_000_000_007, 3);
+        check(-1_000_000_007, 3);
+        check(1_000_000_007, -3);
+        check(-1_000_000_007, -3);
+    }
+
+    #[test]
+    fn exact_division() {
+        check(100, 10);
+        check(0, 7);
+    }
+
+    #[test]
+    fn dividend_smaller_than_divisor() {
+        check(4, 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn divide_by_zero_panics() {
+        BigInt::from_i64(1).div_rem(&BigInt::zero());
+    }
+
+    #[test]
+    fn multi_limb_divisor_matches_reconstruction() {
+        // 123123456789345678901234567890 / 987654321000098765
+        let a = BigInt {
+            neg: false,
+            digits: vec![234567890, 345678901, 123456789, 123],
+        };
+        let b = BigInt {
+            neg: false,
+            digits: vec![98765, 987654321],
+        };
+
+        let (q, r) = a.div_rem(&b);
+        let reconstructed = &(&q * &b) + &r;
+        assert_eq!(reconstructed.digits, a.digits);
+        assert!(cmp_magnitude(&r.digits, &b.digits) == std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn multi_limb_division_reconstructs_across_many_shapes() {
+        let dividends: Vec<Vec<u32>> = vec![
+            (0u64..7)
+                .map(|i| ((i * 123_456_789 + 7) % BASE as u64) as u32)
+                .collect(),
+            (0u64..12)
+                .map(|i| ((i * 987_654_321 + 11) % BASE as u64) as u32)
+                .collect(),
+            vec![1, 0, 0, 0, 5],
+        ];
+        let divisors: Vec<Vec<u32>> = vec![
+            vec![999_999_999, 1],
+            (0u64..4)
+                .map(|i| ((i * 31_415_927 + 3) % BASE as u64) as u32)
+                .collect(),
+            vec![2, 3],
+        ];
+
+        for d in &dividends {
+            for v in &divisors {
+                let a = BigInt {
+                    neg: false,
+                    digits: trim(d.clone()),
+                };
+                let b = BigInt {
+                    neg: false,
+                    digits: trim(v.clone()),
+                };
+                if cmp_magnitude(&a.digits, &b.digits) == std::cmp::Ordering::Less {
+                    continue;
+                }
+
+                let (q, r) = a.div_rem(&b);
+                let reconstructed = &(&q * &b) + &r;
+                assert_eq!(reconstructed.digits, a.digits);
+                assert!(cmp_magnitude(&r.digits, &b.digits) == std::cmp::Ordering::Less);
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::BigInt;
+
+impl BigInt {
+    /// Computes `self^exponent mod modulus` via right-to-left
+    /// square-and-multiply, reducing after every multiplication so operands
+    /// stay bounded by `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero or negative, or if `exponent` is
+    /// negative.
+    pub fn modpow(&self, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+        assert!(
+            !modulus.neg && !modulus.digits.is_empty(),
+            "modpow: modulus must be positive"
+        );
+        assert!(!exponent.neg, "modpow: exponent must be non-negative");
+
+        if exponent.digits.is_empty() {
+            return &BigInt::one() % modulus;
+        }
+
+        let mut base = self % modulus;
+        if base.neg {
+            base = &base + modulus;
+        }
+
+        let mut result = BigInt::one();
+        let mut exp = exponent.clone();
+        while !exp.digits.is_empty() {
+            if exp.bit(0) {
+                result = &(&result * &base) % modulus;
+            }
+            base = &(&base * &base) % modulus;
+            exp = &exp >> 1;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bi(v: i64) -> BigInt {
+        BigInt::from_i64(v)
+    }
+
+    #[test]
+    fn small_known_values() {
+        // 3^4 mod 5 = 81 mod 5 = 1
+        assert_eq!(bi(3).modpow(&bi(4), &bi(5)), bi(1));
+        // 2^10 mod 1000 = 1024 mod 1000 = 24
+        assert_eq!(bi(2).modpow(&bi(10), &bi(1000)), bi(24));
+    }
+
+    #[test]
+    fn exponent_zero_is_one_mod_modulus() {
+        assert_eq!(bi(123).modpow(&bi(0), &bi(7)), bi(1));
+        assert_eq!(bi(123).modpow(&bi(0), &bi(1)), bi(0));
+    }
+
+    #[test]
+    fn base_larger_than_modulus_is_reduced_first() {
+        assert_eq!(bi(17).modpow(&bi(1), &bi(5)), bi(2));
+    }
+
+    #[test]
+    fn negative_base_is_reduced_to_a_positive_residue() {
+        // -2 mod 5 == 3, so (-2)^1 mod 5 == 3.
+        assert_eq!(bi(-2).modpow(&bi(1), &bi(5)), bi(3));
+    }
+
+    #[test]
+    fn matches_repeated_multiplication_for_larger_values() {
+        let base = bi(123_456_789);
+        let modulus = bi(1_000_000_007);
+        let exponent = bi(17);
+
+        let mut expected = BigInt::one();
+        for _ in 0..17 {
+            expected = &(&expected * &base) % &modulus;
+        }
+
+        assert_eq!(base.modpow(&exponent, &modulus), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn zero_modulus_panics() {
+        bi(2).modpow(&bi(3), &bi(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "exponent must be non-negative")]
+    fn negative_exponent_panics() {
+        bi(2).modpow(&bi(-1), &bi(5));
+    }
+}
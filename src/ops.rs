@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{BigInt, BASE};
+
+pub(crate) fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for i in (0..a.len()).rev() {
+        if a[i] != b[i] {
+            return a[i].cmp(&b[i]);
+        }
+    }
+    Ordering::Equal
+}
+
+// Slices may differ in length; the shorter one's missing limbs count as 0.
+pub(crate) fn mag_add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let x = *a.get(i).unwrap_or(&0) as u64;
+        let y = *b.get(i).unwrap_or(&0) as u64;
+        let sum = x + y + carry;
+        digits.push((sum % BASE as u64) as u32);
+        carry = sum / BASE as u64;
+    }
+    if carry > 0 {
+        digits.push(carry as u32);
+    }
+    digits
+}
+
+// Requires a >= b (as magnitudes), computes a - b.
+pub(crate) fn mag_sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &x) in a.iter().enumerate() {
+        let x = x as i64;
+        let y = *b.get(i).unwrap_or(&0) as i64;
+        let mut diff = x - y - borrow;
+        if diff < 0 {
+            diff += BASE as i64;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        digits.push(diff as u32);
+    }
+    digits
+}
+
+// Below this many limbs, schoolbook's better constant factor wins out over
+// Karatsuba's recursion overhead.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+pub(crate) fn mag_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    if a.len() < KARATSUBA_THRESHOLD || b.len() < KARATSUBA_THRESHOLD {
+        return mag_mul_schoolbook(a, b);
+    }
+
+    mag_mul_karatsuba(a, b)
+}
+
+fn mag_mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut digits = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let prod = x as u64 * y as u64 + digits[i + j] as u64 + carry;
+            digits[i + j] = (prod % BASE as u64) as u32;
+            carry = prod / BASE as u64;
+        }
+        let mut k = i + b.len();
+        while carry > 0 {
+            let sum = digits[k] as u64 + carry;
+            digits[k] = (sum % BASE as u64) as u32;
+            carry = sum / BASE as u64;
+            k += 1;
+        }
+    }
+    digits
+}
+
+fn split_at_mid(s: &[u32], m: usize) -> (&[u32], &[u32]) {
+    let m = m.min(s.len());
+    (&s[..m], &s[m..])
+}
+
+pub(crate) fn trim(mut v: Vec<u32>) -> Vec<u32> {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+    v
+}
+
+// Adds `part` into `acc`, shifted up by `shift` limbs, with carry propagation
+// over BASE. `acc` is grown as needed.
+fn mac3(acc: &mut Vec<u32>, part: &[u32], shift: usize) {
+    if part.is_empty() {
+        return;
+    }
+
+    if acc.len() < shift + part.len() {
+        acc.resize(shift + part.len(), 0);
+    }
+
+    let mut carry = 0u64;
+    for (i, &limb) in part.iter().enumerate() {
+        let sum = acc[shift + i] as u64 + limb as u64 + carry;
+        acc[shift + i] = (sum % BASE as u64) as u32;
+        carry = sum / BASE as u64;
+    }
+
+    let mut k = shift + part.len();
+    while carry > 0 {
+        if k == acc.len() {
+            acc.push(0);
+        }
+        let sum = acc[k] as u64 + carry;
+        acc[k] = (sum % BASE as u64) as u32;
+        carry = sum / BASE as u64;
+        k += 1;
+    }
+}
+
+// x = x0 + x1*BASE^m, y = y0 + y1*BASE^m
+// x*y = z0 + z1*BASE^m + z2*BASE^(2m), with z0 = x0*y0, z2 = x1*y1,
+// z1 = (x0+x1)*(y0+y1) - z0 - z2.
+fn mag_mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let m = a.len().max(b.len()).div_ceil(2);
+
+    let (a0, a1) = split_at_mid(a, m);
+    let (b0, b1) = split_at_mid(b, m);
+
+    let z0 = trim(mag_mul(a0, b0));
+    let z2 = trim(mag_mul(a1, b1));
+
+    let a01 = trim(mag_add(a0, a1));
+    let b01 = trim(mag_add(b0, b1));
+    let cross = trim(mag_mul(&a01, &b01));
+    let z1 = trim(mag_sub(&mag_sub(&cross, &z0), &z2));
+
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    mac3(&mut result, &z0, 0);
+    mac3(&mut result, &z1, m);
+    mac3(&mut result, &z2, 2 * m);
+    trim(result)
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt {
+            neg: !self.neg,
+            digits: self.digits.clone(),
+        }
+        .normalize()
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -&self
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.neg == rhs.neg {
+            return BigInt {
+                neg: self.neg,
+                digits: mag_add(&self.digits, &rhs.digits),
+            }
+            .normalize();
+        }
+
+        match cmp_magnitude(&self.digits, &rhs.digits) {
+            Ordering::Equal => BigInt::zero(),
+            Ordering::Greater => BigInt {
+                neg: self.neg,
+                digits: mag_sub(&self.digits, &rhs.digits),
+            }
+            .normalize(),
+            Ordering::Less => BigInt {
+                neg: rhs.neg,
+                digits: mag_sub(&rhs.digits, &self.digits),
+            }
+            .normalize(),
+        }
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: BigInt) -> BigInt {
+        &self + &rhs
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &(-rhs)
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: BigInt) -> BigInt {
+        &self - &rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt {
+            neg: self.neg ^ rhs.neg,
+            digits: mag_mul(&self.digits, &rhs.digits),
+        }
+        .normalize()
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: BigInt) -> BigInt {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_same_sign() {
+        let a = BigInt::from_i64(999_999_999);
+        let b = BigInt::from_i64(1);
+        assert_eq!((a + b).digits, BigInt::from_i64(1_000_000_000).digits);
+    }
+
+    #[test]
+    fn add_opposite_signs() {
+        let a = BigInt::from_i64(5);
+        let b = BigInt::from_i64(-3);
+        assert_eq!((a + b).digits, BigInt::from_i64(2).digits);
+    }
+
+    #[test]
+    fn sub_borrows_across_limb() {
+        let a = BigInt::from_i64(1_000_000_000);
+        let b = BigInt::from_i64(1);
+        let result = a - b;
+        assert_eq!(result.digits, BigInt::from_i64(999_999_999).digits);
+    }
+
+    #[test]
+    fn mul_signs() {
+        let a = BigInt::from_i64(-7);
+        let b = BigInt::from_i64(6);
+        let result = a * b;
+        assert!(result.neg);
+        assert_eq!(result.digits, BigInt::from_i64(42).digits);
+    }
+
+    #[test]
+    fn neg_zero_stays_non_negative() {
+        assert!(!(-BigInt::zero()).neg);
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook_above_threshold() {
+        let a: Vec<u32> = (0..40).map(|i| (i * 37 + 1) % BASE).collect();
+        let b: Vec<u32> = (0..45).map(|i| (i * 101 + 3) % BASE).collect();
+
+        assert_eq!(
+            trim(mag_mul_schoolbook(&a, &b)),
+            trim(mag_mul_karatsuba(&a, &b))
+        );
+    }
+}